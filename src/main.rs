@@ -2,67 +2,326 @@ use std::path::Path; //for handling filesystem paths
 use std::time::Instant; // for measuring how long operations take
 use walkdir::WalkDir; // library for recursively walking directories
 use std::env; // for accessing command-line arguments
+use rayon::prelude::*; // parallel iterators for scanning
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering}; // shared counters/flags across threads
+use std::sync::Arc; // share the stop flag with worker threads
+use crossbeam_channel::Sender; // optional progress reporting channel
+use serde::{Deserialize, Serialize}; // (de)serialization for scan exports
+use std::collections::HashMap; // used by the file-type summary and scan report
+use filesize::PathExt; // actual on-disk size, accounting for sparse files/block rounding
+use std::io::Read; // reading file contents for duplicate hashing
 
-#[derive(Debug)] // printing of the struct for debugging
-struct FileStats { 
+#[derive(Debug, Clone, Serialize, Deserialize)] // printing + export of the struct
+struct FileStats {
     path: String, // stores full path of the file/directory
-    size: u64, // size in bytes (u64 for large files)
+    size: u64, // apparent/logical size in bytes (metadata.len())
+    actual_size: u64, // actual size on disk, accounting for sparse files and block rounding
     file_type: String, // file, directory or other
+    #[serde(with = "unix_time")]
     last_modified: std::time::SystemTime, // last modification time stamp
 }
 
-struct FileSystem { 
+impl FileStats {
+    // picks apparent or on-disk size depending on the configured SizeKind
+    fn size_for(&self, kind: SizeKind) -> u64 {
+        match kind {
+            SizeKind::Apparent => self.size,
+            SizeKind::Disk => self.actual_size,
+        }
+    }
+}
+
+// whether size-based reporting should use the logical file size or the
+// actual space it occupies on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SizeKind {
+    Apparent, // metadata.len(), overstates disk usage for sparse files
+    Disk,     // actual_size, the real on-disk footprint
+}
+
+// (de)serializes SystemTime as a Unix epoch in whole seconds, so exported
+// reports are stable and machine-readable instead of platform-specific
+mod unix_time {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    pub fn serialize<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let secs = time.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        secs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let secs = u64::deserialize(deserializer)?;
+        Ok(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+}
+
+// a full scan's results in a form suitable for exporting to JSON/CSV
+#[derive(Debug, Serialize)]
+struct ScanReport {
+    root_path: String, // directory that was scanned
+    file_count: usize, // number of files found
+    dir_count: usize, // number of directories found
+    total_size: u64, // total apparent bytes across all files
+    total_actual_size: u64, // total on-disk bytes across all files
+    duration_secs: f64, // how long the scan took
+    file_type_summary: HashMap<String, (usize, u64)>, // extension -> (count, bytes)
+    largest_files: Vec<FileStats>, // files picked by the search mode (largest by default, smallest with --smallest)
+    size_distribution: Vec<(String, usize, u64)>, // histogram buckets
+}
+
+impl ScanReport {
+    // builds a report from a completed scan, plus however long that scan took
+    fn build(fs: &FileSystem, duration: std::time::Duration) -> Self {
+        ScanReport {
+            root_path: fs.root_path.clone(),
+            file_count: fs.stats.iter().filter(|s| s.file_type == "file").count(),
+            dir_count: fs.stats.iter().filter(|s| s.file_type == "directory").count(),
+            total_size: fs.stats.iter().filter(|s| s.file_type == "file").map(|s| s.size).sum(),
+            total_actual_size: fs.stats.iter().filter(|s| s.file_type == "file").map(|s| s.actual_size).sum(),
+            duration_secs: duration.as_secs_f64(),
+            file_type_summary: fs.get_file_types_summary(),
+            largest_files: fs.find_files_by_size(fs.search_mode, fs.number_of_files_to_check).into_iter().cloned().collect(),
+            size_distribution: fs.get_size_distribution(),
+        }
+    }
+
+    // writes the report to disk in the requested format
+    fn write_to_file(&self, path: &str, format: &str) -> Result<(), Box<dyn std::error::Error>> {
+        match format {
+            "json" => {
+                let file = std::fs::File::create(path)?;
+                serde_json::to_writer_pretty(file, self)?;
+            }
+            "csv" => {
+                // CSV is inherently tabular, so we export the largest-files
+                // table; the aggregate fields are JSON-only
+                let mut writer = csv::Writer::from_path(path)?;
+                for file in &self.largest_files {
+                    writer.serialize(file)?;
+                }
+                writer.flush()?;
+            }
+            other => return Err(format!("unsupported output format: '{}'", other).into()),
+        }
+        Ok(())
+    }
+}
+
+// reported over the progress channel while a scan is in flight
+#[derive(Debug, Clone)]
+struct ScanProgress {
+    entries_scanned: usize, // entries processed so far
+    total_entries: usize, // total entries discovered for this scan
+}
+
+// requests that any scan sharing this flag (FileSystem::stop_flag) stop as
+// soon as possible. A free function rather than a FileSystem method because
+// the caller that wants to cancel (e.g. a Ctrl-C handler) only ever holds a
+// clone of the flag, never the exclusive &mut FileSystem the running scan has
+fn request_stop(stop_flag: &Arc<AtomicBool>) {
+    stop_flag.store(true, Ordering::SeqCst);
+}
+
+// which end of the size spectrum a size-based search should report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchMode {
+    BiggestFiles,
+    SmallestFiles,
+}
+
+// narrows what scan_directory collects into stats; defaults scan everything
+#[derive(Debug, Clone)]
+struct ScanFilters {
+    allowed_extensions: Vec<String>, // if non-empty, only these extensions are scanned
+    excluded_paths: Vec<String>, // paths matching any of these (anchored prefix or whole component) are skipped
+    min_size: Option<u64>, // skip files smaller than this
+    max_size: Option<u64>, // skip files larger than this
+    recursive: bool, // if false, only scan root_path's direct children
+}
+
+// true if `excl`'s path components appear as a contiguous, component-aligned
+// run somewhere in `path` (e.g. "test" matches ".../test/file.txt" but not
+// ".../latest/file.txt", and "build/tmp" matches only that exact sub-path)
+fn path_matches_excluded(path: &Path, excl: &str) -> bool {
+    let path_components: Vec<_> = path.components().collect();
+    let excl_components: Vec<_> = Path::new(excl).components().collect();
+    if excl_components.is_empty() {
+        return false;
+    }
+    path_components
+        .windows(excl_components.len())
+        .any(|window| window == excl_components.as_slice())
+}
+
+impl Default for ScanFilters {
+    fn default() -> Self {
+        ScanFilters {
+            allowed_extensions: Vec::new(),
+            excluded_paths: Vec::new(),
+            min_size: None,
+            max_size: None,
+            recursive: true, // scan the full tree by default
+        }
+    }
+}
+
+struct FileSystem {
     root_path: String,  // the starting directory path
     stats: Vec<FileStats>, // vector to store all file/directory information
+    stop_flag: Arc<AtomicBool>, // set to request early termination of an in-progress scan
+    search_mode: SearchMode, // biggest or smallest files when reporting by size
+    number_of_files_to_check: usize, // how many files find_files_by_size should return
+    size_mode: SizeKind, // apparent vs on-disk size for size-based reports
+    filters: ScanFilters, // extension/path/size/depth filters applied during scan_directory
 }
 
 impl FileSystem {
     fn new(root_path: &str) -> Self {
         FileSystem {
-            root_path: root_path.to_string(), // convery &str to owned string 
+            root_path: root_path.to_string(), // convery &str to owned string
             stats: Vec::new(),  //initialize empty vector
+            stop_flag: Arc::new(AtomicBool::new(false)), // not stopped yet
+            search_mode: SearchMode::BiggestFiles, // default to the original "largest files" behavior
+            number_of_files_to_check: 50, // default result count
+            size_mode: SizeKind::Apparent, // default to the original logical-size behavior
+            filters: ScanFilters::default(), // no filtering by default
         }
     }
 
-    fn scan_directory(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn scan_directory(
+        &mut self,
+        progress_tx: Option<Sender<ScanProgress>>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         let start_time = Instant::now();  // start timing the operation
         println!("Starting directory scan of '{}'...", self.root_path);
-        self.stats.clear(); // clear any existing stats 
-
-        let mut total_size = 0;
-        let mut file_count = 0;
-        let mut dir_count = 0;
+        self.stats.clear(); // clear any existing stats
+        self.stop_flag.store(false, Ordering::SeqCst); // reset in case of a previous cancelled scan
 
-        for entry in WalkDir::new(&self.root_path) // start at root_path
+        // walk the tree serially first to gather entries, then fan metadata
+        // fetching out across threads with rayon
+        let walker = if self.filters.recursive {
+            WalkDir::new(&self.root_path) // start at root_path
+        } else {
+            WalkDir::new(&self.root_path).max_depth(1) // only root_path's direct children
+        };
+        let entries: Vec<_> = walker
             .into_iter() // create iterator over entries
             .filter_map(|e| e.ok()) // skip entries with errors
-        {
-            let metadata = entry.metadata()?; //get file/directory metadata
-            let file_type = if metadata.is_dir() {
-                dir_count += 1;
-                "directory"
-            } else if metadata.is_file() {
-                file_count += 1;
-                total_size += metadata.len();
-                "file"
-            } else {
-                "other"
-            };
+            .collect();
 
-            self.stats.push(FileStats {
-                path: entry.path().display().to_string(), //convert path to string
-                size: metadata.len(), // get file size 
-                file_type: file_type.to_string(), // store type
-                last_modified: metadata.modified()?, // get modification
-            });
-        }
+        let total_entries = entries.len();
+        let processed = AtomicUsize::new(0); // entries processed so far, shared across threads
+        let stop_flag = Arc::clone(&self.stop_flag);
+        let allowed_extensions = self.filters.allowed_extensions.clone();
+        let excluded_paths = self.filters.excluded_paths.clone();
+        let min_size = self.filters.min_size;
+        let max_size = self.filters.max_size;
+
+        // fold builds up a partial (stats, file_count, dir_count, total_size,
+        // total_actual_size) per thread, then reduce merges the partials into one
+        let (stats, file_count, dir_count, total_size, total_actual_size) = entries
+            .par_iter()
+            .fold(
+                || (Vec::new(), 0usize, 0usize, 0u64, 0u64),
+                |(mut stats, mut file_count, mut dir_count, mut total_size, mut total_actual_size), entry| {
+                    if stop_flag.load(Ordering::Relaxed) {
+                        return (stats, file_count, dir_count, total_size, total_actual_size); // bail out early
+                    }
+
+                    let scanned = processed.fetch_add(1, Ordering::Relaxed) + 1;
+                    if let Some(tx) = &progress_tx {
+                        let _ = tx.send(ScanProgress {
+                            entries_scanned: scanned,
+                            total_entries,
+                        }); // ignore send errors, receiver may have gone away
+                    }
+
+                    let entry_path = entry.path().display().to_string();
+                    if excluded_paths.iter().any(|excl| path_matches_excluded(entry.path(), excl)) {
+                        return (stats, file_count, dir_count, total_size, total_actual_size); // excluded by path
+                    }
+
+                    let metadata = match entry.metadata() {
+                        Ok(metadata) => metadata, // get file/directory metadata
+                        Err(_) => return (stats, file_count, dir_count, total_size, total_actual_size), // skip unreadable entries
+                    };
+
+                    if metadata.is_file() {
+                        if !allowed_extensions.is_empty() {
+                            let ext = Path::new(&entry_path)
+                                .extension()
+                                .and_then(|s| s.to_str())
+                                .unwrap_or("")
+                                .to_lowercase();
+                            if !allowed_extensions.iter().any(|a| a.eq_ignore_ascii_case(&ext)) {
+                                return (stats, file_count, dir_count, total_size, total_actual_size); // wrong extension
+                            }
+                        }
+                        if min_size.is_some_and(|min| metadata.len() < min) {
+                            return (stats, file_count, dir_count, total_size, total_actual_size); // too small
+                        }
+                        if max_size.is_some_and(|max| metadata.len() > max) {
+                            return (stats, file_count, dir_count, total_size, total_actual_size); // too big
+                        }
+                    }
+
+                    // actual on-disk size; falls back to the apparent size if
+                    // the platform can't report block-level usage
+                    let actual_size = entry.path().size_on_disk_fast(&metadata).unwrap_or(metadata.len());
+
+                    let file_type = if metadata.is_dir() {
+                        dir_count += 1;
+                        "directory"
+                    } else if metadata.is_file() {
+                        file_count += 1;
+                        total_size += metadata.len();
+                        total_actual_size += actual_size;
+                        "file"
+                    } else {
+                        "other"
+                    };
+
+                    stats.push(FileStats {
+                        path: entry_path, //convert path to string
+                        size: metadata.len(), // get file size
+                        actual_size, // get on-disk size
+                        file_type: file_type.to_string(), // store type
+                        last_modified: metadata
+                            .modified()
+                            .unwrap_or(std::time::SystemTime::UNIX_EPOCH), // get modification
+                    });
+
+                    (stats, file_count, dir_count, total_size, total_actual_size)
+                },
+            )
+            .reduce(
+                || (Vec::new(), 0usize, 0usize, 0u64, 0u64),
+                |mut a, b| {
+                    a.0.extend(b.0); // merge this thread's stats into the accumulator
+                    a.1 += b.1; // merge file counts
+                    a.2 += b.2; // merge dir counts
+                    a.3 += b.3; // merge total apparent size
+                    a.4 += b.4; // merge total actual size
+                    a
+                },
+            );
+
+        self.stats = stats;
 
         let duration = start_time.elapsed();
         println!("\n📊 Scan Summary:");
         println!("⏱️  Scan completed in {:.2} seconds", duration.as_secs_f64());
         println!("📁 Found {} directories", dir_count);
         println!("📄 Found {} files", file_count);
-        println!("💾 Total size: {} MB", total_size / 1_048_576); // Convert to MB
+        println!("💾 Total apparent size: {} MB", total_size / 1_048_576); // Convert to MB
+        println!("💽 Total size on disk: {} MB", total_actual_size / 1_048_576); // Convert to MB
         Ok(())
     }
 
@@ -70,10 +329,114 @@ impl FileSystem {
         self.stats
             .iter() // iterator over all stats
             .filter(|stat| stat.file_type == "file") // only look at files
-            .map(|stat| stat.size) // extract size
+            .map(|stat| stat.size_for(self.size_mode)) // extract size (apparent or on-disk)
             .sum() // sum all sizes
     }
 
+    // attributes every file's bytes to each of its ancestor directories up
+    // to root_path, giving a recursive (du-style) size per directory
+    fn aggregate_by_directory(&self) -> std::collections::HashMap<String, u64> {
+        let mut sizes: std::collections::HashMap<String, u64> = std::collections::HashMap::new();
+        let root = Path::new(&self.root_path);
+
+        for stat in &self.stats {
+            if stat.file_type != "file" {
+                continue;
+            }
+
+            let mut dir = Path::new(&stat.path).parent();
+            while let Some(d) = dir {
+                if !d.starts_with(root) {
+                    break; // walked above root_path, stop attributing
+                }
+
+                *sizes.entry(d.display().to_string()).or_insert(0) += stat.size_for(self.size_mode);
+
+                if d == root {
+                    break; // reached root_path itself
+                }
+                dir = d.parent();
+            }
+        }
+
+        sizes
+    }
+
+    // largest directories by recursive size, descending
+    fn find_largest_directories(&self, limit: usize) -> Vec<(String, u64)> {
+        let mut dirs: Vec<(String, u64)> = self.aggregate_by_directory().into_iter().collect();
+        dirs.sort_by_key(|d| std::cmp::Reverse(d.1)); // sort by size (largest first)
+        dirs.truncate(limit);
+        dirs
+    }
+
+    // groups files with identical content using a staged hash comparison:
+    // same size -> same partial (first 16KB) hash -> same full hash. Each
+    // returned cluster has >= 2 files confirmed identical.
+    fn find_duplicates(&self) -> Vec<Vec<&FileStats>> {
+        // stage 0: files of different size can never be duplicates
+        let mut by_size: HashMap<u64, Vec<&FileStats>> = HashMap::new();
+        for stat in &self.stats {
+            if stat.file_type == "file" {
+                by_size.entry(stat.size).or_default().push(stat);
+            }
+        }
+
+        let mut clusters = Vec::new();
+
+        for same_size in by_size.into_values() {
+            if same_size.len() < 2 {
+                continue; // nothing to compare
+            }
+
+            // stage 1: group by a cheap hash of just the first 16KB
+            let mut by_partial: HashMap<String, Vec<&FileStats>> = HashMap::new();
+            for stat in same_size {
+                if let Some(hash) = Self::partial_hash(&stat.path) {
+                    by_partial.entry(hash).or_default().push(stat);
+                }
+            }
+
+            for same_partial in by_partial.into_values() {
+                if same_partial.len() < 2 {
+                    continue;
+                }
+
+                // stage 2: confirm partial-hash collisions with a full hash
+                let mut by_full: HashMap<String, Vec<&FileStats>> = HashMap::new();
+                for stat in same_partial {
+                    if let Some(hash) = Self::full_hash(&stat.path) {
+                        by_full.entry(hash).or_default().push(stat);
+                    }
+                }
+
+                clusters.extend(by_full.into_values().filter(|group| group.len() >= 2));
+            }
+        }
+
+        clusters
+    }
+
+    // hashes just the first 16KB of a file, cheap enough to run on every
+    // same-size candidate before committing to a full read
+    fn partial_hash(path: &str) -> Option<String> {
+        const PARTIAL_BYTES: u64 = 16 * 1024;
+        let file = std::fs::File::open(path).ok()?;
+        // a single read() may return short of PARTIAL_BYTES even before EOF,
+        // so read to the end of the (size-limited) take() adapter instead
+        let mut buf = Vec::new();
+        file.take(PARTIAL_BYTES).read_to_end(&mut buf).ok()?;
+        Some(blake3::hash(&buf).to_hex().to_string())
+    }
+
+    // hashes the full contents of a file to confirm a partial-hash collision
+    fn full_hash(path: &str) -> Option<String> {
+        let mut file = std::fs::File::open(path).ok()?;
+        let mut hasher = blake3::Hasher::new();
+        std::io::copy(&mut file, &mut hasher).ok()?;
+        Some(hasher.finalize().to_hex().to_string())
+    }
+
     fn get_file_types_summary(&self) -> std::collections::HashMap<String, (usize, u64)> {
         let mut extensions = std::collections::HashMap::new();
         
@@ -94,16 +457,81 @@ impl FileSystem {
         extensions
     }
 
-    fn find_largest_files(&self, limit: usize) -> Vec<&FileStats> {
+    // returns up to `limit` files, biggest- or smallest-first depending on `mode`
+    fn find_files_by_size(&self, mode: SearchMode, limit: usize) -> Vec<&FileStats> {
         let mut files: Vec<&FileStats> = self.stats
             .iter()  // iterate over all stats
             .filter(|stat| stat.file_type == "file") // only look at files
+            .filter(|stat| mode != SearchMode::SmallestFiles || stat.size_for(self.size_mode) > 0) // skip zero-byte files when hunting for the smallest
             .collect(); // collect into vector
-        
-        files.sort_by(|a, b| b.size.cmp(&a.size)); // sort by size (largest first)
+
+        match mode {
+            SearchMode::BiggestFiles => files.sort_by_key(|f| std::cmp::Reverse(f.size_for(self.size_mode))), // largest first
+            SearchMode::SmallestFiles => files.sort_by_key(|f| f.size_for(self.size_mode)), // smallest first
+        }
         files.truncate(limit); // keep only the first 'limit' files
         files
     }
+
+    // buckets every file into power-of-two/ten size ranges, returning
+    // (label, count, total_bytes) per bucket in ascending size order
+    fn get_size_distribution(&self) -> Vec<(String, usize, u64)> {
+        // the fixed bucket labels, in ascending size order
+        let labels = ["0B", "1B-1KB", "1KB-1MB", "1MB-1GB", "1GB-1TB", ">1TB"];
+        let mut counts = [0usize; 6]; // per-bucket file count
+        let mut totals = [0u64; 6]; // per-bucket byte total
+
+        for stat in &self.stats {
+            if stat.file_type != "file" {
+                continue;
+            }
+
+            let size = stat.size_for(self.size_mode);
+            let bucket = Self::size_bucket(size);
+            counts[bucket] += 1;
+            totals[bucket] += size;
+        }
+
+        labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (label.to_string(), counts[i], totals[i]))
+            .collect()
+    }
+
+    // maps a file size to a bucket index by floor(log2(size)), with size 0
+    // kept in its own bucket so empty marker files don't skew the 1B-1KB bucket
+    fn size_bucket(size: u64) -> usize {
+        if size == 0 {
+            return 0;
+        }
+
+        // floor(log2(size)) via the position of the highest set bit
+        let log2 = 63 - size.leading_zeros();
+        match log2 {
+            0..=9 => 1,   // up to 2^10 bytes: 1B-1KB
+            10..=19 => 2, // up to 2^20 bytes: 1KB-1MB
+            20..=29 => 3, // up to 2^30 bytes: 1MB-1GB
+            30..=39 => 4, // up to 2^40 bytes: 1GB-1TB
+            _ => 5,       // anything bigger: >1TB
+        }
+    }
+}
+
+// inserts a root index before the file extension so scanning multiple roots
+// with --output doesn't have each root's report clobber the last one's,
+// e.g. ("out.json", 1) -> "out.1.json"
+fn per_root_output_path(path: &str, index: usize) -> String {
+    let p = Path::new(path);
+    let stem = p.file_stem().and_then(|s| s.to_str()).unwrap_or(path);
+    let suffixed = match p.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{}.{}.{}", stem, index, ext),
+        None => format!("{}.{}", stem, index),
+    };
+    match p.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        Some(parent) => parent.join(suffixed).to_string_lossy().into_owned(),
+        None => suffixed,
+    }
 }
 
 fn format_size(size: u64) -> String {
@@ -123,16 +551,52 @@ fn format_size(size: u64) -> String {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Get directory from command line args or use current directory
-    let path = env::args().nth(1).unwrap_or_else(|| ".".to_string());
-    let mut fs = FileSystem::new(&path); // create new filesystem instance
-    fs.scan_directory()?; // scan the directory
+// scans a single root path and prints its full report; returns the root's
+// total file size so callers can accumulate a grand total across roots
+fn scan_and_report(
+    path: &str,
+    search_mode: SearchMode,
+    number_of_files_to_check: usize,
+    size_mode: SizeKind,
+    filters: ScanFilters,
+    output: Option<(&str, &str)>, // (output file, format) when --output/--format were passed
+    abort_flag: Arc<AtomicBool>, // shared with main's Ctrl-C handler; set to request an early stop
+) -> Result<u64, Box<dyn std::error::Error>> {
+    let scan_start = Instant::now(); // time just the scan, for the exported report
+    let mut fs = FileSystem::new(path); // create new filesystem instance
+    fs.search_mode = search_mode;
+    fs.number_of_files_to_check = number_of_files_to_check;
+    fs.size_mode = size_mode;
+    fs.filters = filters;
+    fs.stop_flag = abort_flag; // let Ctrl-C cancel this scan in progress
+
+    // print coarse progress as the scan runs, and let the scan be cancelled
+    // early by whatever holds fs.stop_flag (main's Ctrl-C handler)
+    let (progress_tx, progress_rx) = crossbeam_channel::unbounded::<ScanProgress>();
+    let printer = std::thread::spawn(move || {
+        for progress in progress_rx {
+            if progress.total_entries == 0 {
+                continue;
+            }
+            if progress.entries_scanned == progress.total_entries || progress.entries_scanned % 1000 == 0 {
+                println!("⏳ {}/{} entries scanned", progress.entries_scanned, progress.total_entries);
+            }
+        }
+    });
+    fs.scan_directory(Some(progress_tx))?; // scan the directory
+    let _ = printer.join();
+    let scan_duration = scan_start.elapsed();
+
+    if let Some((output_path, format)) = output {
+        let report = ScanReport::build(&fs, scan_duration);
+        report.write_to_file(output_path, format)?;
+        println!("\n💾 Wrote {} report to '{}'", format, output_path);
+    }
 
     // Print file type distribution
     println!("\n📋 File Type Distribution:");
     let mut type_summary: Vec<_> = fs.get_file_types_summary().into_iter().collect();
-    type_summary.sort_by(|a, b| b.1.0.cmp(&a.1.0)); // Sort by count
+    type_summary.sort_by_key(|t| std::cmp::Reverse(t.1.0)); // Sort by count
     for (ext, (count, size)) in type_summary {
         println!(".{:<12} {} files ({} total)",
             ext,
@@ -140,16 +604,168 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             format_size(size)
         );
     }
-    
-    // Print largest files
-    println!("\n🔍 Largest Files:");
-    for (i, file) in fs.find_largest_files(10).iter().enumerate() {
-        println!("{}. {:<50} {}", 
+
+    // Print files selected by the configured search mode (biggest by default)
+    let section_title = match fs.search_mode {
+        SearchMode::BiggestFiles => "🔍 Largest Files:",
+        SearchMode::SmallestFiles => "🔍 Smallest Files:",
+    };
+    println!("\n{}", section_title);
+    let files = fs.find_files_by_size(fs.search_mode, fs.number_of_files_to_check);
+    for (i, file) in files.iter().enumerate() {
+        println!("{}. {:<50} {}",
             i + 1,
-            file.path.replace(&path, "."),
-            format_size(file.size)
+            file.path.replace(path, "."),
+            format_size(file.size_for(fs.size_mode))
         );
     }
 
+    // Print size distribution histogram
+    println!("\n📈 Size Distribution:");
+    let distribution = fs.get_size_distribution();
+    let max_count = distribution.iter().map(|(_, count, _)| *count).max().unwrap_or(0);
+    const BAR_WIDTH: usize = 40; // max bar length in characters
+    for (label, count, total) in &distribution {
+        let bar_len = (count * BAR_WIDTH).checked_div(max_count).unwrap_or(0); // scale bar to at most BAR_WIDTH chars; the terminal's actual width is not queried
+        let bar: String = "#".repeat(bar_len);
+        println!("{:<10} {:<40} {:>6} files ({})",
+            label,
+            bar,
+            count,
+            format_size(*total)
+        );
+    }
+
+    // Print largest directories
+    println!("\n🗂️  Largest Directories:");
+    for (i, (dir, size)) in fs.find_largest_directories(10).iter().enumerate() {
+        println!("{}. {:<50} {}",
+            i + 1,
+            dir.replace(path, "."),
+            format_size(*size)
+        );
+    }
+
+    // Print duplicate files and how much space reclaiming them would free
+    println!("\n🧬 Duplicate Files:");
+    let duplicates = fs.find_duplicates();
+    let reclaimable: u64 = duplicates
+        .iter()
+        .map(|cluster| cluster[0].size_for(fs.size_mode) * (cluster.len() as u64 - 1))
+        .sum();
+    println!("Found {} duplicate clusters, {} reclaimable", duplicates.len(), format_size(reclaimable));
+    for (i, cluster) in duplicates.iter().enumerate() {
+        println!("Cluster {} ({} copies, {} each):", i + 1, cluster.len(), format_size(cluster[0].size_for(fs.size_mode)));
+        for file in cluster {
+            println!("  - {}", file.path.replace(path, "."));
+        }
+    }
+
+    Ok(fs.get_directory_size())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect(); // skip the binary name
+    let mut roots: Vec<String> = Vec::new(); // directories to scan
+    let mut search_mode = SearchMode::BiggestFiles;
+    let mut number_of_files_to_check = 50usize;
+    let mut output_path: Option<String> = None;
+    let mut output_format = "json".to_string();
+    let mut size_mode = SizeKind::Apparent;
+    let mut filters = ScanFilters::default();
+
+    // walk the args looking for flags; any other plain argument is taken
+    // as a root directory to scan
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--smallest" => {
+                let n = iter.next().ok_or("--smallest requires a number")?;
+                search_mode = SearchMode::SmallestFiles;
+                number_of_files_to_check = n.parse()?;
+            }
+            "--biggest" => {
+                let n = iter.next().ok_or("--biggest requires a number")?;
+                search_mode = SearchMode::BiggestFiles;
+                number_of_files_to_check = n.parse()?;
+            }
+            "--output" => {
+                output_path = Some(iter.next().ok_or("--output requires a file path")?);
+            }
+            "--format" => {
+                output_format = iter.next().ok_or("--format requires 'json' or 'csv'")?;
+            }
+            "--apparent" => size_mode = SizeKind::Apparent,
+            "--disk" => size_mode = SizeKind::Disk,
+            "--ext" => {
+                let ext = iter.next().ok_or("--ext requires an extension")?;
+                // Path::extension() never includes the leading dot, so strip
+                // one off here in case the user wrote the conventional ".log" form
+                filters.allowed_extensions.push(ext.trim_start_matches('.').to_string());
+            }
+            "--exclude" => {
+                let excl = iter.next().ok_or("--exclude requires a path")?;
+                filters.excluded_paths.push(excl);
+            }
+            "--min-size" => {
+                let n = iter.next().ok_or("--min-size requires a byte count")?;
+                filters.min_size = Some(n.parse()?);
+            }
+            "--max-size" => {
+                let n = iter.next().ok_or("--max-size requires a byte count")?;
+                filters.max_size = Some(n.parse()?);
+            }
+            "--no-recursive" => filters.recursive = false,
+            other => roots.push(other.to_string()),
+        }
+    }
+
+    if roots.is_empty() {
+        roots.push(".".to_string()); // default to current directory
+    }
+
+    // shared across roots so Ctrl-C cancels the in-progress scan and skips
+    // any roots still queued behind it
+    let abort_requested = Arc::new(AtomicBool::new(false));
+    let handler_flag = Arc::clone(&abort_requested);
+    if let Err(e) = ctrlc::set_handler(move || {
+        println!("\n⚠️  Stop requested, finishing current entries and exiting...");
+        request_stop(&handler_flag);
+    }) {
+        eprintln!("warning: failed to install Ctrl-C handler: {}", e);
+    }
+
+    let mut grand_total = 0u64;
+    for (i, path) in roots.iter().enumerate() {
+        if abort_requested.load(Ordering::SeqCst) {
+            println!("Scan cancelled, skipping remaining roots.");
+            break;
+        }
+
+        // with a single root, write exactly the path the user asked for;
+        // with multiple roots, suffix by index so each root's report survives
+        let root_output_path = output_path.as_ref().map(|p| {
+            if roots.len() > 1 {
+                per_root_output_path(p, i)
+            } else {
+                p.clone()
+            }
+        });
+        let output = root_output_path.as_deref().map(|p| (p, output_format.as_str()));
+        grand_total += scan_and_report(
+            path,
+            search_mode,
+            number_of_files_to_check,
+            size_mode,
+            filters.clone(),
+            output,
+            Arc::clone(&abort_requested),
+        )?;
+    }
+
+    if roots.len() > 1 {
+        println!("\n💰 Grand total across {} roots: {}", roots.len(), format_size(grand_total));
+    }
+
     Ok(())
 }